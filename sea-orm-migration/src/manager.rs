@@ -1,8 +1,8 @@
 use super::{IntoSchemaManagerConnection, SchemaManagerConnection};
 use sea_orm::sea_query::{
-    ForeignKeyCreateStatement, ForeignKeyDropStatement, IndexCreateStatement, IndexDropStatement,
-    SelectStatement, TableAlterStatement, TableCreateStatement, TableDropStatement,
-    TableRenameStatement, TableTruncateStatement,
+    Expr, ForeignKeyCreateStatement, ForeignKeyDropStatement, IndexCreateStatement,
+    IndexDropStatement, IntoTableRef, Query, SelectStatement, TableAlterStatement,
+    TableCreateStatement, TableDropStatement, TableRenameStatement, TableTruncateStatement,
     extension::postgres::{TypeAlterStatement, TypeCreateStatement, TypeDropStatement},
 };
 use sea_orm::{ConnectionTrait, DbBackend, DbErr, StatementBuilder};
@@ -172,6 +172,28 @@ impl SchemaManager<'_> {
 
         res.try_get("", "has_index")
     }
+
+    /// Check if a table has no rows, e.g. to make a seeding migration idempotent
+    pub async fn table_is_empty<T>(&self, table: T) -> Result<bool, DbErr>
+    where
+        T: IntoTableRef,
+    {
+        let stmt = Query::select()
+            .expr_as(
+                Expr::exists(Query::select().expr(Expr::val(1)).from(table).take()),
+                "has_rows",
+            )
+            .take();
+
+        let res = self
+            .conn
+            .query_one(&stmt)
+            .await?
+            .ok_or_else(|| DbErr::Custom("Failed to check table is empty".to_owned()))?;
+
+        let has_rows: bool = res.try_get("", "has_rows")?;
+        Ok(!has_rows)
+    }
 }
 
 pub(crate) async fn has_table<C, T>(conn: &C, _table: T) -> Result<bool, DbErr>
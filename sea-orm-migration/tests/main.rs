@@ -208,6 +208,9 @@ where
     assert!(manager.has_column("cake", "name").await?);
     assert!(manager.has_column("fruit", "cake_id").await?);
 
+    assert!(!manager.table_is_empty("cake").await?);
+    assert!(manager.table_is_empty("fruit").await?);
+
     // Tests rolling back changes of "migrate down" when running migration on Postgres
     if matches!(db.get_database_backend(), DbBackend::Postgres) {
         println!("\nRoll back changes when encounter errors");
@@ -81,7 +81,7 @@ impl From<DatabaseConnectionType> for DatabaseConnection {
 
 /// The type of database backend for real world databases.
 /// This is enabled by feature flags as specified in the crate documentation
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum DatabaseBackend {
     /// A MySQL backend
@@ -3,7 +3,7 @@ use crate::DbBackend;
 pub use sea_query::audit::{AuditTrait, Error as AuditError, QueryAccessAudit};
 use sea_query::{MysqlQueryBuilder, PostgresQueryBuilder, SqliteQueryBuilder, inject_parameters};
 pub use sea_query::{Value, Values};
-use std::fmt;
+use std::{fmt, mem};
 
 /// Defines an SQL statement
 #[derive(Debug, Clone, PartialEq)]
@@ -17,6 +17,15 @@ pub struct Statement {
     pub db_backend: DbBackend,
 }
 
+/// A hashable key identifying a [Statement] by its SQL, backend and parameter types.
+/// Parameter values are not part of the key, see [Statement::cache_key]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatementCacheKey {
+    sql: String,
+    db_backend: DbBackend,
+    value_types: Vec<mem::Discriminant<Value>>,
+}
+
 /// Any type that can build a [Statement]
 pub trait StatementBuilder: Sync {
     /// Method to build a [Statement]
@@ -50,6 +59,33 @@ impl Statement {
         Self::from_string_values_tuple(db_backend, (sql, Values(values.into_iter().collect())))
     }
 
+    /// Get a key for caching this statement, e.g. to reuse a prepared statement.
+    /// Two statements share the same key if they have the same SQL, backend and parameter types,
+    /// regardless of the parameter values.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, Statement};
+    ///
+    /// let sql = r#"SELECT "cake"."name" FROM "cake" WHERE "cake"."id" = $1"#;
+    /// let a = Statement::from_sql_and_values(DbBackend::Postgres, sql, [1i32.into()]);
+    /// let b = Statement::from_sql_and_values(DbBackend::Postgres, sql, [2i32.into()]);
+    /// let c = Statement::from_sql_and_values(DbBackend::Postgres, sql, [2i64.into()]);
+    ///
+    /// assert_eq!(a.cache_key(), b.cache_key());
+    /// assert_ne!(b.cache_key(), c.cache_key());
+    /// ```
+    pub fn cache_key(&self) -> StatementCacheKey {
+        StatementCacheKey {
+            sql: self.sql.clone(),
+            db_backend: self.db_backend,
+            value_types: self
+                .values
+                .iter()
+                .flat_map(|values| values.iter().map(mem::discriminant))
+                .collect(),
+        }
+    }
+
     pub(crate) fn from_string_values_tuple<T>(db_backend: DbBackend, stmt: (T, Values)) -> Statement
     where
         T: Into<String>,